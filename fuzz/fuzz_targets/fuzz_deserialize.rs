@@ -0,0 +1,12 @@
+#![no_main]
+
+use dlog_proof::DLogProof;
+use libfuzzer_sys::fuzz_target;
+
+// Arbitrary input fed through the serde/JSON path must return an error rather
+// than panicking.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let _ = serde_json::from_str::<DLogProof>(s);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use dlog_proof::DLogProof;
+use libfuzzer_sys::fuzz_target;
+
+// Arbitrary bytes must never panic the binary decoder, and anything that
+// decodes must re-encode to the exact same bytes (round-trip stability).
+fuzz_target!(|data: &[u8]| {
+    if let Ok(proof) = DLogProof::from_bytes(data) {
+        let bytes = proof.to_bytes();
+        assert_eq!(&bytes[..], data);
+        let reparsed = DLogProof::from_bytes(&bytes).expect("re-decoding a valid proof must succeed");
+        assert_eq!(proof, reparsed);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use dlog_proof::DLogProof;
+use k256::ProjectivePoint;
+use libfuzzer_sys::fuzz_target;
+
+// Verifying an attacker-controlled proof against a fixed statement must never
+// panic, regardless of the bytes that decoded into the proof.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(proof) = DLogProof::from_bytes(data) {
+        let _ = proof.verify("fuzz", 0, ProjectivePoint::GENERATOR);
+    }
+});
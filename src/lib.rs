@@ -1,4 +1,8 @@
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::ops::{Add, AddAssign, Mul};
+
+use serde::{
+    de::Error as DeError, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer,
+};
 
 use k256::{
     elliptic_curve::{
@@ -9,70 +13,193 @@ use k256::{
 };
 use rand::rngs::OsRng;
 use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 pub fn generate_random_number() -> Scalar<Secp256k1> {
     let mut rng = OsRng;
     Scalar::<Secp256k1>::random(&mut rng)
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
-pub struct DLogProof {
-    #[serde(
-        serialize_with = "serialize_projective_point",
-        deserialize_with = "deserialize_projective_point"
-    )]
-    t: ProjectivePoint,
-    #[serde(
-        serialize_with = "serialize_scalar",
-        deserialize_with = "deserialize_scalar"
-    )]
-    s: Scalar<Secp256k1>,
-}
-
-fn serialize_projective_point<S>(t: &ProjectivePoint, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    let t_hex = hex::encode(t.to_bytes());
-    serializer.serialize_str(&t_hex)
-}
-
-fn deserialize_projective_point<'de, D>(deserializer: D) -> Result<ProjectivePoint, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let t_hex: String = Deserialize::deserialize(deserializer)?;
-    let t_bytes = hex::decode(t_hex).map_err(serde::de::Error::custom)?;
-    let point = ProjectivePoint::from_bytes(&GenericArray::clone_from_slice(&t_bytes)).unwrap();
-    Ok(point)
-}
-
-fn serialize_scalar<S>(s: &Scalar<Secp256k1>, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    let s_bytes = s.to_bytes().to_vec();
-    let s_hex = hex::encode(s_bytes);
-    serializer.serialize_str(&s_hex)
-}
-
-fn deserialize_scalar<'de, D>(deserializer: D) -> Result<Scalar<Secp256k1>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let s_hex: String = Deserialize::deserialize(deserializer)?;
-    let s_bytes = hex::decode(s_hex).map_err(serde::de::Error::custom)?;
-    Scalar::<Secp256k1>::from_repr(GenericArray::clone_from_slice(&s_bytes))
-        .into_option()
-        .ok_or_else(|| serde::de::Error::custom("Invalid scalar"))
-}
-
-impl DLogProof {
-    pub fn hash_points(
+/// A discrete-log witness that wipes itself from memory when dropped.
+///
+/// Holding the secret scalar in this wrapper rather than a bare `Scalar`
+/// narrows the window in which a memory disclosure could leak it, following the
+/// secret-key hygiene used by curve libraries such as curv.
+#[derive(Clone)]
+pub struct Witness(Scalar<Secp256k1>);
+
+impl Witness {
+    pub fn random() -> Self {
+        Witness(generate_random_number())
+    }
+
+    pub fn new(scalar: Scalar<Secp256k1>) -> Self {
+        Witness(scalar)
+    }
+
+    pub fn scalar(&self) -> &Scalar<Secp256k1> {
+        &self.0
+    }
+}
+
+impl core::ops::Deref for Witness {
+    type Target = Scalar<Secp256k1>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Zeroize for Witness {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for Witness {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Witness {}
+
+/// The group operations the Schnorr/Fiat-Shamir proof actually relies on.
+///
+/// Implementing this for a `k256`-compatible group lets the same protocol run
+/// over a different curve (e.g. a Ristretto or BabyJubJub backend) without
+/// touching `prove`/`verify`. `DLogProof` is an alias for
+/// `DLogProofGeneric<Secp256k1>`, so existing callers keep working unchanged.
+pub trait DLogGroup {
+    type Point: Copy
+        + PartialEq
+        + Add<Self::Point, Output = Self::Point>
+        + Mul<Self::Scalar, Output = Self::Point>;
+    type Scalar: Copy
+        + PartialEq
+        + Add<Self::Scalar, Output = Self::Scalar>
+        + AddAssign<Self::Scalar>
+        + Mul<Self::Scalar, Output = Self::Scalar>;
+
+    fn generator() -> Self::Point;
+    fn identity() -> Self::Point;
+    fn scalar_zero() -> Self::Scalar;
+    fn random_scalar() -> Self::Scalar;
+    fn scalar_is_zero(s: &Self::Scalar) -> bool;
+    /// Wipe a secret scalar (e.g. the ephemeral nonce) from memory.
+    fn zeroize_scalar(s: &mut Self::Scalar);
+
+    /// Compressed encoding fed into the Fiat-Shamir transcript.
+    fn point_to_transcript(p: &Self::Point) -> Vec<u8>;
+    /// Big-endian byte encodings of the point's affine coordinate field
+    /// elements (`[x, y]`), used by the arithmetic-circuit transcript.
+    fn point_coordinates(p: &Self::Point) -> Vec<Vec<u8>>;
+    /// Canonical wire encoding of a point (compressed).
+    fn point_to_bytes(p: &Self::Point) -> Vec<u8>;
+    fn point_from_bytes(bytes: &[u8]) -> Option<Self::Point>;
+    fn scalar_to_bytes(s: &Self::Scalar) -> Vec<u8>;
+    fn scalar_from_bytes(bytes: &[u8]) -> Option<Self::Scalar>;
+    /// Interpret a 32-byte hash digest as a scalar; `None` if the digest is
+    /// not below the group order (it is not reduced).
+    fn scalar_from_hash(bytes: &[u8]) -> Option<Self::Scalar>;
+}
+
+impl DLogGroup for Secp256k1 {
+    type Point = ProjectivePoint;
+    type Scalar = Scalar<Secp256k1>;
+
+    fn generator() -> Self::Point {
+        ProjectivePoint::GENERATOR
+    }
+
+    fn identity() -> Self::Point {
+        ProjectivePoint::IDENTITY
+    }
+
+    fn scalar_zero() -> Self::Scalar {
+        Scalar::<Secp256k1>::ZERO
+    }
+
+    fn random_scalar() -> Self::Scalar {
+        generate_random_number()
+    }
+
+    fn scalar_is_zero(s: &Self::Scalar) -> bool {
+        bool::from(s.is_zero())
+    }
+
+    fn zeroize_scalar(s: &mut Self::Scalar) {
+        s.zeroize();
+    }
+
+    fn point_to_transcript(p: &Self::Point) -> Vec<u8> {
+        p.to_encoded_point(true).as_bytes().to_vec()
+    }
+
+    fn point_coordinates(p: &Self::Point) -> Vec<Vec<u8>> {
+        let encoded = p.to_encoded_point(false);
+        let mut coords = Vec::with_capacity(2);
+        if let Some(x) = encoded.x() {
+            coords.push(x.to_vec());
+        }
+        if let Some(y) = encoded.y() {
+            coords.push(y.to_vec());
+        }
+        coords
+    }
+
+    fn point_to_bytes(p: &Self::Point) -> Vec<u8> {
+        p.to_bytes().to_vec()
+    }
+
+    fn point_from_bytes(bytes: &[u8]) -> Option<Self::Point> {
+        if bytes.len() != 33 {
+            return None;
+        }
+        ProjectivePoint::from_bytes(&GenericArray::clone_from_slice(bytes)).into_option()
+    }
+
+    fn scalar_to_bytes(s: &Self::Scalar) -> Vec<u8> {
+        s.to_bytes().to_vec()
+    }
+
+    fn scalar_from_bytes(bytes: &[u8]) -> Option<Self::Scalar> {
+        if bytes.len() != 32 {
+            return None;
+        }
+        Scalar::<Secp256k1>::from_repr(GenericArray::clone_from_slice(bytes)).into_option()
+    }
+
+    fn scalar_from_hash(bytes: &[u8]) -> Option<Self::Scalar> {
+        if bytes.len() != 32 {
+            return None;
+        }
+        Scalar::<Secp256k1>::from_repr(GenericArray::clone_from_slice(bytes)).into_option()
+    }
+}
+
+/// Strategy for deriving the Fiat-Shamir challenge from the transcript.
+///
+/// The default [`Sha256Hasher`] reproduces the original SHA-256 transcript.
+/// A Poseidon-based hasher (behind the `poseidon` feature) produces challenges
+/// that are cheap to re-derive inside an arithmetic circuit, so proofs from this
+/// crate can be verified inside a SNARK.
+pub trait ChallengeHasher<C: DLogGroup> {
+    fn hash_points(sid: &str, pid: u64, points: &[C::Point])
+        -> Result<C::Scalar, anyhow::Error>;
+}
+
+/// SHA-256 transcript: absorb `sid`, `pid`, then each point's compressed
+/// encoding, and read the digest as a scalar (an error if it is not below the
+/// group order).
+pub struct Sha256Hasher;
+
+impl<C: DLogGroup> ChallengeHasher<C> for Sha256Hasher {
+    fn hash_points(
         sid: &str,
         pid: u64,
-        points: &[ProjectivePoint],
-    ) -> Result<Scalar<Secp256k1>, anyhow::Error> {
+        points: &[C::Point],
+    ) -> Result<C::Scalar, anyhow::Error> {
         let result = points
             .iter()
             .fold(
@@ -80,52 +207,385 @@ impl DLogProof {
                     .chain_update(sid.as_bytes())
                     .chain_update(pid.to_le_bytes()),
                 |mut hasher, point| {
-                    hasher.update(point.to_encoded_point(true).as_bytes());
+                    hasher.update(C::point_to_transcript(point));
                     hasher
                 },
             )
             .finalize();
 
-        Scalar::<Secp256k1>::from_repr(GenericArray::clone_from_slice(&result))
-            .into_option()
+        C::scalar_from_hash(&result)
             .ok_or_else(|| anyhow::Error::msg("Failed to create scalar from hash"))
     }
+}
+
+pub struct DLogProofGeneric<C: DLogGroup> {
+    t: C::Point,
+    s: C::Scalar,
+}
+
+/// A discrete-log proof over the default [`Secp256k1`] group. This concrete
+/// alias keeps `DLogProof::prove(...)` and friends resolving without a turbofish
+/// so existing callers are unaffected; use [`DLogProofGeneric`] for other curves.
+pub type DLogProof = DLogProofGeneric<Secp256k1>;
+
+impl<C: DLogGroup> PartialEq for DLogProofGeneric<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t && self.s == other.s
+    }
+}
+
+impl<C: DLogGroup> Eq for DLogProofGeneric<C> {}
+
+impl<C: DLogGroup> core::fmt::Debug for DLogProofGeneric<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DLogProof")
+            .field("t", &hex::encode(C::point_to_bytes(&self.t)))
+            .field("s", &hex::encode(C::scalar_to_bytes(&self.s)))
+            .finish()
+    }
+}
+
+impl<C: DLogGroup> Serialize for DLogProofGeneric<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DLogProof", 2)?;
+        state.serialize_field("t", &hex::encode(C::point_to_bytes(&self.t)))?;
+        state.serialize_field("s", &hex::encode(C::scalar_to_bytes(&self.s)))?;
+        state.end()
+    }
+}
+
+impl<'de, C: DLogGroup> Deserialize<'de> for DLogProofGeneric<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            t: String,
+            s: String,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+
+        let t_bytes = hex::decode(&raw.t).map_err(DeError::custom)?;
+        let t = C::point_from_bytes(&t_bytes).ok_or_else(|| DeError::custom("Invalid point"))?;
+        if t == C::identity() {
+            return Err(DeError::custom("Point is the identity"));
+        }
+
+        let s_bytes = hex::decode(&raw.s).map_err(DeError::custom)?;
+        let s = C::scalar_from_bytes(&s_bytes).ok_or_else(|| DeError::custom("Invalid scalar"))?;
+
+        Ok(Self { t, s })
+    }
+}
+
+impl<C: DLogGroup> DLogProofGeneric<C> {
+    pub fn hash_points(
+        sid: &str,
+        pid: u64,
+        points: &[C::Point],
+    ) -> Result<C::Scalar, anyhow::Error> {
+        <Sha256Hasher as ChallengeHasher<C>>::hash_points(sid, pid, points)
+    }
 
     pub fn prove(
         sid: &str,
         pid: u64,
-        x: Scalar<Secp256k1>,
-        y: ProjectivePoint,
+        x: C::Scalar,
+        y: C::Point,
     ) -> Result<Self, anyhow::Error> {
-        let r = generate_random_number();
-        Ok(Self {
-            t: ProjectivePoint::GENERATOR * r,
-            s: r + x * Self::hash_points(
-                sid,
-                pid,
-                &[
-                    ProjectivePoint::GENERATOR,
-                    y,
-                    ProjectivePoint::GENERATOR * r,
-                ],
-            )?,
-        })
-    }
-
-    pub fn verify(&self, sid: &str, pid: u64, y: ProjectivePoint) -> Result<bool, anyhow::Error> {
-        let c = Self::hash_points(sid, pid, &[ProjectivePoint::GENERATOR, y, self.t])?;
-        Ok(ProjectivePoint::GENERATOR * self.s == self.t + (y * c))
-    }
-
-    pub fn t(&self) -> &ProjectivePoint {
+        Self::prove_with::<Sha256Hasher>(sid, pid, x, y)
+    }
+
+    pub fn prove_with<H: ChallengeHasher<C>>(
+        sid: &str,
+        pid: u64,
+        x: C::Scalar,
+        y: C::Point,
+    ) -> Result<Self, anyhow::Error> {
+        if C::scalar_is_zero(&x) {
+            return Err(anyhow::Error::msg("Witness is zero"));
+        }
+        if y == C::identity() {
+            return Err(anyhow::Error::msg("Statement point is the identity"));
+        }
+
+        let mut r = C::random_scalar();
+        let t = C::generator() * r;
+        let c = H::hash_points(sid, pid, &[C::generator(), y, t])?;
+        let s = r + x * c;
+        C::zeroize_scalar(&mut r);
+        Ok(Self { t, s })
+    }
+
+    pub fn verify(&self, sid: &str, pid: u64, y: C::Point) -> Result<bool, anyhow::Error> {
+        self.verify_with::<Sha256Hasher>(sid, pid, y)
+    }
+
+    pub fn verify_with<H: ChallengeHasher<C>>(
+        &self,
+        sid: &str,
+        pid: u64,
+        y: C::Point,
+    ) -> Result<bool, anyhow::Error> {
+        // Reject degenerate inputs that would make the check trivially pass.
+        if y == C::identity() || self.t == C::identity() || C::scalar_is_zero(&self.s) {
+            return Ok(false);
+        }
+
+        let c = H::hash_points(sid, pid, &[C::generator(), y, self.t])?;
+        Ok(C::generator() * self.s == self.t + (y * c))
+    }
+
+    pub fn verify_batch(
+        items: &[(&str, u64, C::Point, &DLogProofGeneric<C>)],
+    ) -> Result<bool, anyhow::Error> {
+        // Collapse the N individual checks `G·s_i == t_i + y_i·c_i` into a single
+        // equation `G·(Σ ρ_i·s_i) == Σ ρ_i·t_i + Σ (ρ_i·c_i)·y_i`. The ρ_i are
+        // sampled fresh from `OsRng` on every call so a batch of individually
+        // invalid proofs cannot be crafted to cancel out.
+        let mut s_combined = C::scalar_zero();
+        let mut rhs = C::identity();
+
+        for (sid, pid, y, proof) in items {
+            // Apply the same degenerate-input rejection as `verify_with`, so a
+            // statement that `verify` would reject cannot slip through the
+            // aggregate check.
+            if *y == C::identity()
+                || proof.t == C::identity()
+                || C::scalar_is_zero(&proof.s)
+            {
+                return Ok(false);
+            }
+
+            let rho = loop {
+                let rho = C::random_scalar();
+                if !C::scalar_is_zero(&rho) {
+                    break rho;
+                }
+            };
+            let c = Self::hash_points(sid, *pid, &[C::generator(), *y, proof.t])?;
+
+            s_combined += rho * proof.s;
+            rhs = rhs + (proof.t * rho + *y * (rho * c));
+        }
+
+        Ok(C::generator() * s_combined == rhs)
+    }
+
+    pub fn t(&self) -> &C::Point {
         &self.t
     }
 
-    pub fn s(&self) -> &Scalar<Secp256k1> {
+    pub fn s(&self) -> &C::Scalar {
         &self.s
     }
 }
 
+impl<C: DLogGroup> DLogProofGeneric<C> {
+    pub fn prove_many(
+        sid: &str,
+        pid: u64,
+        statements: &[(C::Scalar, C::Point)],
+    ) -> Result<DLogProofsGeneric<C>, anyhow::Error> {
+        // One Fiat-Shamir challenge binds every sub-statement: hash `sid`, `pid`
+        // and the full list `[G, y_0, t_0, y_1, t_1, …]`. Sharing `c` keeps the
+        // aggregate compact and stops sub-proofs from different sessions being
+        // mixed and matched.
+        for (x, y) in statements {
+            if C::scalar_is_zero(x) {
+                return Err(anyhow::Error::msg("Witness is zero"));
+            }
+            if *y == C::identity() {
+                return Err(anyhow::Error::msg("Statement point is the identity"));
+            }
+        }
+
+        let mut rs: Vec<C::Scalar> = statements.iter().map(|_| C::random_scalar()).collect();
+
+        let mut points = Vec::with_capacity(1 + 2 * statements.len());
+        points.push(C::generator());
+        for ((_, y), r) in statements.iter().zip(rs.iter()) {
+            points.push(*y);
+            points.push(C::generator() * *r);
+        }
+
+        let c = Self::hash_points(sid, pid, &points)?;
+
+        let proofs = statements
+            .iter()
+            .zip(rs.iter())
+            .map(|((x, _), r)| DLogProofGeneric {
+                t: C::generator() * *r,
+                s: *r + *x * c,
+            })
+            .collect();
+
+        for r in rs.iter_mut() {
+            C::zeroize_scalar(r);
+        }
+
+        Ok(DLogProofsGeneric { proofs })
+    }
+}
+
+/// An AND-composition of several discrete-log proofs sharing one challenge.
+pub struct DLogProofsGeneric<C: DLogGroup> {
+    proofs: Vec<DLogProofGeneric<C>>,
+}
+
+impl<C: DLogGroup> PartialEq for DLogProofsGeneric<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.proofs == other.proofs
+    }
+}
+
+impl<C: DLogGroup> Eq for DLogProofsGeneric<C> {}
+
+impl<C: DLogGroup> core::fmt::Debug for DLogProofsGeneric<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("DLogProofs").field(&self.proofs).finish()
+    }
+}
+
+impl<C: DLogGroup> Serialize for DLogProofsGeneric<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.proofs.serialize(serializer)
+    }
+}
+
+impl<'de, C: DLogGroup> Deserialize<'de> for DLogProofsGeneric<C> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let proofs = Vec::<DLogProofGeneric<C>>::deserialize(deserializer)?;
+        Ok(Self { proofs })
+    }
+}
+
+impl<C: DLogGroup> DLogProofsGeneric<C> {
+    pub fn verify_many(
+        &self,
+        sid: &str,
+        pid: u64,
+        ys: &[C::Point],
+    ) -> Result<bool, anyhow::Error> {
+        if ys.len() != self.proofs.len() {
+            return Ok(false);
+        }
+
+        // Reject degenerate sub-statements up front, matching `verify_with`.
+        for (y, proof) in ys.iter().zip(self.proofs.iter()) {
+            if *y == C::identity()
+                || proof.t == C::identity()
+                || C::scalar_is_zero(&proof.s)
+            {
+                return Ok(false);
+            }
+        }
+
+        let mut points = Vec::with_capacity(1 + 2 * ys.len());
+        points.push(C::generator());
+        for (y, proof) in ys.iter().zip(self.proofs.iter()) {
+            points.push(*y);
+            points.push(proof.t);
+        }
+
+        let c = DLogProofGeneric::<C>::hash_points(sid, pid, &points)?;
+
+        Ok(ys.iter().zip(self.proofs.iter()).all(|(y, proof)| {
+            C::generator() * proof.s == proof.t + (*y * c)
+        }))
+    }
+
+    pub fn proofs(&self) -> &[DLogProofGeneric<C>] {
+        &self.proofs
+    }
+}
+
+impl DLogProofGeneric<Secp256k1> {
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut out = [0u8; 65];
+        out[..33].copy_from_slice(self.t.to_bytes().as_slice());
+        out[33..].copy_from_slice(self.s.to_bytes().as_slice());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, anyhow::Error> {
+        if bytes.len() != 65 {
+            return Err(anyhow::Error::msg("Invalid proof length"));
+        }
+
+        let t = <Secp256k1 as DLogGroup>::point_from_bytes(&bytes[..33])
+            .ok_or_else(|| anyhow::Error::msg("Invalid point encoding"))?;
+        if t == ProjectivePoint::IDENTITY {
+            return Err(anyhow::Error::msg("Point is the identity"));
+        }
+
+        let s = <Secp256k1 as DLogGroup>::scalar_from_bytes(&bytes[33..])
+            .ok_or_else(|| anyhow::Error::msg("Invalid scalar"))?;
+
+        Ok(Self { t, s })
+    }
+}
+
+/// Poseidon transcript for SNARK-friendly verification. Enabled with the
+/// `poseidon` feature. The challenge is produced by absorbing `sid`, `pid`, and
+/// the affine coordinate field elements of each point into a Poseidon sponge
+/// (two-element permutation applied sequentially) and squeezing one scalar.
+#[cfg(feature = "poseidon")]
+pub use self::poseidon::PoseidonHasher;
+
+#[cfg(feature = "poseidon")]
+mod poseidon {
+    use super::{ChallengeHasher, DLogGroup};
+
+    use ark_bn254::Fr;
+    use ark_ff::{BigInteger, PrimeField};
+    use light_poseidon::{Poseidon, PoseidonHasher as _};
+
+    pub struct PoseidonHasher;
+
+    impl<C: DLogGroup> ChallengeHasher<C> for PoseidonHasher {
+        fn hash_points(
+            sid: &str,
+            pid: u64,
+            points: &[C::Point],
+        ) -> Result<C::Scalar, anyhow::Error> {
+            let mut sponge = Poseidon::<Fr>::new_circom(2).map_err(anyhow::Error::msg)?;
+
+            let mut state = Fr::from(0u64);
+            let mut absorb = |bytes: &[u8]| -> Result<(), anyhow::Error> {
+                let next = Fr::from_be_bytes_mod_order(bytes);
+                state = sponge.hash(&[state, next]).map_err(anyhow::Error::msg)?;
+                Ok(())
+            };
+
+            absorb(sid.as_bytes())?;
+            absorb(&pid.to_le_bytes())?;
+            for point in points {
+                // Absorb each affine coordinate field element, as an in-circuit
+                // verifier would see the point, rather than a byte blob.
+                for coord in C::point_coordinates(point) {
+                    absorb(&coord)?;
+                }
+            }
+
+            let digest = state.into_bigint().to_bytes_be();
+            C::scalar_from_hash(&digest)
+                .ok_or_else(|| anyhow::Error::msg("Failed to create scalar from hash"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,6 +620,151 @@ mod tests {
             .expect("Failed to verify proof"));
     }
 
+    #[test]
+    fn test_dlog_proof_verify_batch() {
+        let sid = "sid";
+
+        let mut proofs = Vec::new();
+        for pid in 0..4u64 {
+            let x = generate_random_number();
+            let y = ProjectivePoint::GENERATOR * x;
+            let proof = DLogProof::prove(sid, pid, x, y).expect("Failed to create proof");
+            proofs.push((pid, y, proof));
+        }
+
+        let items: Vec<_> = proofs
+            .iter()
+            .map(|(pid, y, proof)| (sid, *pid, *y, proof))
+            .collect();
+
+        assert!(DLogProof::verify_batch(&items).expect("Failed to verify batch"));
+    }
+
+    #[test]
+    fn test_dlog_proof_verify_batch_invalid() {
+        let sid = "sid";
+
+        let mut proofs = Vec::new();
+        for pid in 0..4u64 {
+            let x = generate_random_number();
+            let y = ProjectivePoint::GENERATOR * x;
+            let proof = DLogProof::prove(sid, pid, x, y).expect("Failed to create proof");
+            proofs.push((pid, y, proof));
+        }
+
+        // Corrupt one of the statements so the aggregated check must fail.
+        let y_invalid = ProjectivePoint::GENERATOR * generate_random_number();
+        proofs[2].1 = y_invalid;
+
+        let items: Vec<_> = proofs
+            .iter()
+            .map(|(pid, y, proof)| (sid, *pid, *y, proof))
+            .collect();
+
+        assert!(!DLogProof::verify_batch(&items).expect("Failed to verify batch"));
+    }
+
+    #[test]
+    fn test_dlog_proof_sha256_hasher_explicit() {
+        let sid = "sid";
+        let pid = 1u64;
+
+        let x = generate_random_number();
+        let y = ProjectivePoint::GENERATOR * x;
+
+        let dlog_proof =
+            DLogProof::prove_with::<Sha256Hasher>(sid, pid, x, y).expect("Failed to create proof");
+
+        assert!(dlog_proof
+            .verify_with::<Sha256Hasher>(sid, pid, y)
+            .expect("Failed to verify proof"));
+    }
+
+    #[test]
+    fn test_dlog_proof_prove_many() {
+        let sid = "sid";
+        let pid = 1u64;
+
+        let statements: Vec<_> = (0..3)
+            .map(|_| {
+                let x = generate_random_number();
+                (x, ProjectivePoint::GENERATOR * x)
+            })
+            .collect();
+
+        let proofs = DLogProof::prove_many(sid, pid, &statements).expect("Failed to prove");
+
+        let ys: Vec<_> = statements.iter().map(|(_, y)| *y).collect();
+        assert!(proofs.verify_many(sid, pid, &ys).expect("Failed to verify"));
+    }
+
+    #[test]
+    fn test_dlog_proof_prove_many_invalid() {
+        let sid = "sid";
+        let pid = 1u64;
+
+        let statements: Vec<_> = (0..3)
+            .map(|_| {
+                let x = generate_random_number();
+                (x, ProjectivePoint::GENERATOR * x)
+            })
+            .collect();
+
+        let proofs = DLogProof::prove_many(sid, pid, &statements).expect("Failed to prove");
+
+        let mut ys: Vec<_> = statements.iter().map(|(_, y)| *y).collect();
+        ys[1] = ProjectivePoint::GENERATOR * generate_random_number();
+
+        assert!(!proofs.verify_many(sid, pid, &ys).expect("Failed to verify"));
+    }
+
+    #[test]
+    fn test_prove_rejects_degenerate_inputs() {
+        let x = generate_random_number();
+        let y = ProjectivePoint::GENERATOR * x;
+
+        assert!(DLogProof::prove("sid", 1, Scalar::<Secp256k1>::ZERO, y).is_err());
+        assert!(DLogProof::prove("sid", 1, x, ProjectivePoint::IDENTITY).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_identity() {
+        // All-zero compressed encoding decodes to the identity point.
+        let t = hex::encode([0u8; 33]);
+        let s = hex::encode([0u8; 32]);
+        let json = format!(r#"{{"t":"{t}","s":"{s}"}}"#);
+        assert!(serde_json::from_str::<DLogProof>(&json).is_err());
+    }
+
+    #[test]
+    fn test_witness_proves() {
+        let sid = "sid";
+        let pid = 1u64;
+
+        let witness = Witness::random();
+        let y = ProjectivePoint::GENERATOR * *witness;
+
+        let proof = DLogProof::prove(sid, pid, *witness, y).expect("Failed to create proof");
+        assert!(proof.verify(sid, pid, y).expect("Failed to verify proof"));
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn test_dlog_proof_poseidon_hasher_roundtrip() {
+        let sid = "sid";
+        let pid = 1u64;
+
+        let x = generate_random_number();
+        let y = ProjectivePoint::GENERATOR * x;
+
+        let dlog_proof = DLogProof::prove_with::<PoseidonHasher>(sid, pid, x, y)
+            .expect("Failed to create proof");
+
+        assert!(dlog_proof
+            .verify_with::<PoseidonHasher>(sid, pid, y)
+            .expect("Failed to verify proof"));
+    }
+
     #[test]
     fn test_dlog_proof_serialization() {
         let sid = "sid";
@@ -175,4 +780,27 @@ mod tests {
 
         assert_eq!(dlog_proof, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_dlog_proof_bytes_roundtrip() {
+        let sid = "sid";
+        let pid = 1u64;
+
+        let x = generate_random_number();
+        let y = ProjectivePoint::GENERATOR * x;
+
+        let dlog_proof = DLogProof::prove(sid, pid, x, y).expect("Failed to create proof");
+
+        let bytes = dlog_proof.to_bytes();
+        assert_eq!(bytes.len(), 65);
+
+        let decoded = DLogProof::from_bytes(&bytes).expect("Failed to decode proof");
+        assert_eq!(dlog_proof, decoded);
+    }
+
+    #[test]
+    fn test_dlog_proof_from_bytes_rejects_bad_input() {
+        assert!(DLogProof::from_bytes(&[0u8; 64]).is_err());
+        assert!(DLogProof::from_bytes(&[0u8; 65]).is_err());
+    }
+}